@@ -0,0 +1,211 @@
+//! Historical replay / backtesting
+//!
+//! Drives a `PriceMonitor` and a `SimulatedExecutor` from a recorded series
+//! of `Tick`s instead of a live feed, so alert thresholds and order logic
+//! can be validated against history before going live. A `ReplayClock`
+//! keeps staleness gating honest: each tick is evaluated as of its own
+//! historical timestamp, not wall-clock time.
+
+mod ticks;
+
+pub use ticks::{load_ticks, Tick};
+
+use crate::execution::{ExecutionConfig, OrderExecutor, SimulatedExecutor};
+use crate::price_monitor::{MonitorConfig, PriceMonitor, ReplayClock};
+use crate::types::{OrderParams, OrderSide};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Summary metrics produced by a backtest run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BacktestReport {
+    pub alerts_triggered: usize,
+    pub fills: usize,
+    /// Net PnL from closed positions. Fills are priced at
+    /// `SimulatedExecutor`'s `average_price`, which already has
+    /// `ExecutionConfig::fee_bps` baked in alongside slippage, so this
+    /// figure is already net of fees — do not subtract `fees_paid` from it.
+    pub realized_pnl: f64,
+    /// Total fees paid across all fills, for visibility only. Fees on fills
+    /// that opened or closed a closed position are already reflected in
+    /// `realized_pnl`; fees on a position still open at the end of the run
+    /// are counted here but not in `realized_pnl`.
+    pub fees_paid: f64,
+    pub win_rate: f64,
+    pub max_drawdown: f64,
+}
+
+/// An open position taken in response to an alert, waiting to be closed by
+/// the next opposite-direction fill for the same token.
+struct OpenPosition {
+    side: OrderSide,
+    entry_price: f64,
+    amount: f64,
+}
+
+/// Replays a fixed series of `Tick`s through a `PriceMonitor` and a
+/// `SimulatedExecutor`. On every alert, trades in the direction of the
+/// price move; realized PnL, fees, win rate, and max drawdown are tallied
+/// as positions are opened and closed.
+pub struct Backtester {
+    monitor: Arc<PriceMonitor>,
+    executor: SimulatedExecutor,
+    clock: Arc<ReplayClock>,
+    execution_config: ExecutionConfig,
+    trade_size: f64,
+}
+
+impl Backtester {
+    /// Create a backtester with the given monitor gating configuration and
+    /// a fixed order size used for every alert-triggered trade.
+    pub fn new(monitor_config: MonitorConfig, trade_size: f64) -> Self {
+        Self::with_execution_config(monitor_config, ExecutionConfig::default(), trade_size)
+    }
+
+    /// Create a backtester with custom monitor gating and execution
+    /// (slippage/fee) configuration.
+    pub fn with_execution_config(
+        monitor_config: MonitorConfig,
+        execution_config: ExecutionConfig,
+        trade_size: f64,
+    ) -> Self {
+        // Overwritten by the timestamp of the first replayed tick in `run`;
+        // this initial value is never observed.
+        let clock = Arc::new(ReplayClock::new(Utc::now()));
+        let monitor = Arc::new(PriceMonitor::with_clock(monitor_config, clock.clone()));
+        let executor = SimulatedExecutor::with_config(monitor.clone(), execution_config);
+        Self { monitor, executor, clock, execution_config, trade_size }
+    }
+
+    /// Replay `ticks` in order, using `alert_threshold_percent` for every
+    /// token encountered, and return the summary report.
+    pub fn run(&self, ticks: &[Tick], alert_threshold_percent: f64) -> Result<BacktestReport, String> {
+        let mut report = BacktestReport::default();
+        let mut open_positions: HashMap<String, OpenPosition> = HashMap::new();
+        let mut alerted_tokens = HashMap::new();
+        let mut cumulative_pnl = 0.0_f64;
+        let mut peak_pnl = 0.0_f64;
+        let mut closed_trades = 0usize;
+        let mut winning_trades = 0usize;
+
+        for tick in ticks {
+            self.clock.set(tick.timestamp);
+
+            if !alerted_tokens.contains_key(&tick.token) {
+                self.monitor.add_alert(tick.token.clone(), alert_threshold_percent)?;
+                alerted_tokens.insert(tick.token.clone(), ());
+            }
+
+            let change_percent = self.monitor.update_price_from(
+                tick.token.clone(),
+                "backtest".to_string(),
+                tick.price,
+                tick.conf,
+                tick.status,
+                tick.timestamp,
+            )?;
+
+            let Some(change_percent) = change_percent else {
+                continue;
+            };
+            report.alerts_triggered += 1;
+
+            let side = if change_percent > 0.0 { OrderSide::Buy } else { OrderSide::Sell };
+            let order = OrderParams {
+                token: tick.token.clone(),
+                side,
+                amount: self.trade_size,
+                price: Some(tick.price),
+            };
+
+            let fill = match self.executor.submit(order) {
+                Ok(fill) => fill,
+                Err(_) => continue, // stale/untradeable at this point in history; skip the trade
+            };
+            report.fills += 1;
+            report.fees_paid += fill.filled_amount * fill.average_price * (self.execution_config.fee_bps / 10_000.0).abs();
+
+            match open_positions.remove(&tick.token) {
+                Some(position) if position.side != side => {
+                    let closed_amount = position.amount.min(fill.filled_amount);
+                    let pnl = match position.side {
+                        OrderSide::Buy => (fill.average_price - position.entry_price) * closed_amount,
+                        OrderSide::Sell => (position.entry_price - fill.average_price) * closed_amount,
+                    };
+
+                    cumulative_pnl += pnl;
+                    report.realized_pnl += pnl;
+                    peak_pnl = peak_pnl.max(cumulative_pnl);
+                    report.max_drawdown = report.max_drawdown.max(peak_pnl - cumulative_pnl);
+
+                    closed_trades += 1;
+                    if pnl > 0.0 {
+                        winning_trades += 1;
+                    }
+                }
+                _ => {
+                    open_positions.insert(
+                        tick.token.clone(),
+                        OpenPosition { side, entry_price: fill.average_price, amount: fill.filled_amount },
+                    );
+                }
+            }
+        }
+
+        report.win_rate = if closed_trades > 0 {
+            winning_trades as f64 / closed_trades as f64
+        } else {
+            0.0
+        };
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PriceStatus;
+
+    fn tick(token: &str, price: f64, minute: i64) -> Tick {
+        Tick {
+            token: token.to_string(),
+            price,
+            conf: price * 0.001,
+            status: PriceStatus::Trading,
+            timestamp: Utc::now() + chrono::Duration::minutes(minute),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_trade_produces_realized_pnl() {
+        let backtester = Backtester::new(MonitorConfig::default(), 10.0);
+
+        let ticks = vec![
+            tick("SOL", 100.0, 0), // baseline, no alert yet
+            tick("SOL", 110.0, 1), // +10% triggers a buy
+            tick("SOL", 90.0, 2),  // -18% triggers a sell, closing the buy at a loss
+        ];
+
+        let report = backtester.run(&ticks, 2.0).unwrap();
+
+        assert_eq!(report.alerts_triggered, 2);
+        assert_eq!(report.fills, 2);
+        assert!(report.realized_pnl < 0.0); // bought at 110, closed at 90
+        assert!(report.fees_paid > 0.0);
+        assert_eq!(report.win_rate, 0.0);
+    }
+
+    #[test]
+    fn test_no_alerts_below_threshold() {
+        let backtester = Backtester::new(MonitorConfig::default(), 10.0);
+
+        let ticks = vec![tick("SOL", 100.0, 0), tick("SOL", 100.5, 1)];
+        let report = backtester.run(&ticks, 5.0).unwrap();
+
+        assert_eq!(report.alerts_triggered, 0);
+        assert_eq!(report.fills, 0);
+    }
+}
@@ -0,0 +1,127 @@
+//! Loading historical ticks for a `Backtester` run
+
+use crate::types::PriceStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A single historical price observation to replay through a `Backtester`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tick {
+    pub token: String,
+    pub price: f64,
+    #[serde(default)]
+    pub conf: f64,
+    #[serde(default = "default_status")]
+    pub status: PriceStatus,
+    pub timestamp: DateTime<Utc>,
+}
+
+fn default_status() -> PriceStatus {
+    PriceStatus::Trading
+}
+
+/// Load ticks from a `.csv` (`token,price,conf,status,timestamp` header,
+/// RFC3339 timestamps) or `.json` (array of `Tick`) file, sorted by
+/// timestamp so they can be replayed in order.
+pub fn load_ticks(path: &str) -> Result<Vec<Tick>, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| format!("failed to read {path}: {e}"))?;
+
+    let mut ticks = if Path::new(path).extension().and_then(|e| e.to_str()) == Some("csv") {
+        parse_csv(&contents)?
+    } else {
+        serde_json::from_str(&contents).map_err(|e| format!("failed to parse {path} as JSON: {e}"))?
+    };
+
+    ticks.sort_by_key(|t| t.timestamp);
+    Ok(ticks)
+}
+
+/// Minimal CSV parser: no quoting support, just `,`-separated fields with a
+/// header row naming the columns. Good enough for the fixed tick schema.
+fn parse_csv(contents: &str) -> Result<Vec<Tick>, String> {
+    let mut lines = contents.lines();
+    let header = lines.next().ok_or("empty CSV file")?;
+    let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_csv_row(&columns, line))
+        .collect()
+}
+
+fn parse_csv_row(columns: &[&str], line: &str) -> Result<Tick, String> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() != columns.len() {
+        return Err(format!(
+            "CSV row has {} fields, expected {}: {line}",
+            fields.len(),
+            columns.len()
+        ));
+    }
+
+    let mut token = None;
+    let mut price = None;
+    let mut conf = 0.0;
+    let mut status = PriceStatus::Trading;
+    let mut timestamp = None;
+
+    for (column, value) in columns.iter().zip(fields.iter()) {
+        match *column {
+            "token" => token = Some((*value).to_string()),
+            "price" => price = Some(value.parse::<f64>().map_err(|e| e.to_string())?),
+            "conf" => conf = value.parse::<f64>().map_err(|e| e.to_string())?,
+            "status" => status = parse_status(value)?,
+            "timestamp" => {
+                timestamp = Some(
+                    DateTime::parse_from_rfc3339(value)
+                        .map_err(|e| e.to_string())?
+                        .with_timezone(&Utc),
+                )
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Tick {
+        token: token.ok_or("CSV row missing token column")?,
+        price: price.ok_or("CSV row missing price column")?,
+        conf,
+        status,
+        timestamp: timestamp.ok_or("CSV row missing timestamp column")?,
+    })
+}
+
+fn parse_status(value: &str) -> Result<PriceStatus, String> {
+    match value.to_lowercase().as_str() {
+        "trading" => Ok(PriceStatus::Trading),
+        "halted" => Ok(PriceStatus::Halted),
+        "auction" => Ok(PriceStatus::Auction),
+        "unknown" => Ok(PriceStatus::Unknown),
+        other => Err(format!("unknown price status: {other}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_keeps_file_order() {
+        let csv = "token,price,conf,status,timestamp\n\
+                   SOL,101.0,0.1,Trading,2024-01-01T00:01:00Z\n\
+                   SOL,100.0,0.1,Trading,2024-01-01T00:00:00Z\n";
+
+        let ticks = parse_csv(csv).unwrap();
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[0].price, 101.0); // not sorted yet, that's load_ticks' job
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_unknown_status() {
+        let csv = "token,price,conf,status,timestamp\n\
+                   SOL,100.0,0.1,Bogus,2024-01-01T00:00:00Z\n";
+
+        assert!(parse_csv(csv).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+//! Order execution
+//!
+//! Fills orders against the prices tracked by a `PriceMonitor`. The only
+//! implementation today is a simulated executor; a live executor would
+//! implement the same `OrderExecutor` trait against a real venue.
+
+mod simulated;
+
+pub use simulated::{ExecutionConfig, SimulatedExecutor};
+
+use crate::types::{OrderParams, OrderResult, OrderStatus};
+
+/// Submits, cancels, and reports on the lifecycle of orders.
+pub trait OrderExecutor: Send + Sync {
+    /// Submit an order for execution, returning once it reaches a terminal
+    /// (or currently-known) status.
+    fn submit(&self, params: OrderParams) -> Result<OrderResult, String>;
+
+    /// Cancel a resting order. Fails if the order is unknown or already filled.
+    fn cancel(&self, order_id: &str) -> Result<(), String>;
+
+    /// Look up the current status of a previously submitted order.
+    fn status(&self, order_id: &str) -> Result<Option<OrderStatus>, String>;
+}
@@ -0,0 +1,242 @@
+//! Simulated order executor
+//!
+//! Fills orders against whatever price `PriceMonitor` currently publishes,
+//! applying a configurable slippage and fee model. Market orders are
+//! rejected outright if the underlying price is stale or untradeable,
+//! matching the guard `PriceMonitor::update_price` already applies before
+//! letting a reading drive an alert.
+
+use super::OrderExecutor;
+use crate::price_monitor::PriceMonitor;
+use crate::types::{OrderParams, OrderResult, OrderSide, OrderStatus, PriceStatus};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Slippage and fee assumptions used to turn a reference price into a fill.
+#[derive(Debug, Clone, Copy)]
+pub struct ExecutionConfig {
+    /// Adverse price movement applied against the order, in basis points.
+    pub slippage_bps: f64,
+    /// Trading fee applied against the order, in basis points.
+    pub fee_bps: f64,
+}
+
+impl Default for ExecutionConfig {
+    fn default() -> Self {
+        Self { slippage_bps: 5.0, fee_bps: 10.0 }
+    }
+}
+
+/// Simulated `OrderExecutor` backed by a `PriceMonitor`.
+pub struct SimulatedExecutor {
+    monitor: Arc<PriceMonitor>,
+    config: ExecutionConfig,
+    orders: Mutex<HashMap<String, OrderResult>>,
+    next_id: AtomicU64,
+}
+
+impl SimulatedExecutor {
+    /// Create a simulated executor with default slippage/fee assumptions.
+    pub fn new(monitor: Arc<PriceMonitor>) -> Self {
+        Self::with_config(monitor, ExecutionConfig::default())
+    }
+
+    /// Create a simulated executor with custom slippage/fee assumptions.
+    pub fn with_config(monitor: Arc<PriceMonitor>, config: ExecutionConfig) -> Self {
+        Self {
+            monitor,
+            config,
+            orders: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    fn next_order_id(&self) -> String {
+        format!("ord_{}", self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// The price to fill against: the order's limit price if given, or the
+    /// monitor's current consensus price for a market order. Market orders
+    /// are rejected if the token's price is stale or not `Trading`.
+    fn reference_price(&self, params: &OrderParams) -> Result<f64, String> {
+        if let Some(limit_price) = params.price {
+            return Ok(limit_price);
+        }
+
+        let is_fresh = self.monitor.is_price_fresh(&params.token)?;
+        let with_conf = self.monitor.get_price_with_conf(&params.token)?;
+
+        match with_conf {
+            Some((price, _, PriceStatus::Trading)) if is_fresh => Ok(price),
+            _ => Err(format!(
+                "cannot fill market order for {}: price is stale or untradeable",
+                params.token
+            )),
+        }
+    }
+
+    fn record(&self, order_id: &str, result: &OrderResult) -> Result<(), String> {
+        let mut orders = self.orders.lock().map_err(|e| e.to_string())?;
+        orders.insert(order_id.to_string(), result.clone());
+        Ok(())
+    }
+}
+
+impl OrderExecutor for SimulatedExecutor {
+    fn submit(&self, params: OrderParams) -> Result<OrderResult, String> {
+        let start = Instant::now();
+        let order_id = self.next_order_id();
+
+        let pending = OrderResult {
+            order_id: order_id.clone(),
+            status: OrderStatus::Pending,
+            filled_amount: 0.0,
+            average_price: 0.0,
+            latency_ms: 0,
+        };
+        self.record(&order_id, &pending)?;
+
+        let reference_price = match self.reference_price(&params) {
+            Ok(price) => price,
+            Err(e) => {
+                let failed = OrderResult {
+                    status: OrderStatus::Failed,
+                    latency_ms: start.elapsed().as_millis() as u64,
+                    ..pending
+                };
+                self.record(&order_id, &failed)?;
+                return Err(e);
+            }
+        };
+
+        let side_sign = match params.side {
+            OrderSide::Buy => 1.0,
+            OrderSide::Sell => -1.0,
+        };
+        let slipped_price = reference_price * (1.0 + side_sign * self.config.slippage_bps / 10_000.0);
+        let average_price = slipped_price * (1.0 + side_sign * self.config.fee_bps / 10_000.0);
+
+        let partially_filled = OrderResult {
+            order_id: order_id.clone(),
+            status: OrderStatus::PartiallyFilled,
+            filled_amount: params.amount / 2.0,
+            average_price,
+            latency_ms: start.elapsed().as_millis() as u64,
+        };
+        self.record(&order_id, &partially_filled)?;
+
+        let filled = OrderResult {
+            order_id,
+            status: OrderStatus::Filled,
+            filled_amount: params.amount,
+            average_price,
+            latency_ms: start.elapsed().as_millis() as u64,
+        };
+        self.record(&filled.order_id, &filled)?;
+
+        Ok(filled)
+    }
+
+    fn cancel(&self, order_id: &str) -> Result<(), String> {
+        let mut orders = self.orders.lock().map_err(|e| e.to_string())?;
+        match orders.get_mut(order_id) {
+            Some(order) if matches!(order.status, OrderStatus::Filled) => {
+                Err(format!("order {} is already filled", order_id))
+            }
+            Some(order) => {
+                order.status = OrderStatus::Cancelled;
+                Ok(())
+            }
+            None => Err(format!("unknown order {}", order_id)),
+        }
+    }
+
+    fn status(&self, order_id: &str) -> Result<Option<OrderStatus>, String> {
+        let orders = self.orders.lock().map_err(|e| e.to_string())?;
+        Ok(orders.get(order_id).map(|o| o.status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::price_monitor::PriceMonitor;
+    use chrono::Utc;
+
+    fn monitor_with_price(token: &str, price: f64) -> Arc<PriceMonitor> {
+        let monitor = Arc::new(PriceMonitor::new());
+        monitor
+            .update_price(token.to_string(), price, price * 0.001, PriceStatus::Trading, Utc::now())
+            .unwrap();
+        monitor
+    }
+
+    #[test]
+    fn test_market_order_fills_at_monitor_price_with_slippage() {
+        let monitor = monitor_with_price("SOL", 100.0);
+        let executor = SimulatedExecutor::new(monitor);
+
+        let result = executor
+            .submit(OrderParams { token: "SOL".to_string(), side: OrderSide::Buy, amount: 10.0, price: None })
+            .unwrap();
+
+        assert!(matches!(result.status, OrderStatus::Filled));
+        assert_eq!(result.filled_amount, 10.0);
+        assert!(result.average_price > 100.0); // buy slippage/fees push the price up
+    }
+
+    #[test]
+    fn test_market_order_rejected_without_price() {
+        let monitor = Arc::new(PriceMonitor::new());
+        let executor = SimulatedExecutor::new(monitor);
+
+        let result = executor.submit(OrderParams {
+            token: "NOFEED".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: None,
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_limit_order_fills_without_monitor_price() {
+        let monitor = Arc::new(PriceMonitor::new());
+        let executor = SimulatedExecutor::new(monitor);
+
+        let result = executor
+            .submit(OrderParams { token: "NOFEED".to_string(), side: OrderSide::Sell, amount: 5.0, price: Some(50.0) })
+            .unwrap();
+
+        assert!(matches!(result.status, OrderStatus::Filled));
+        assert!(result.average_price < 50.0); // sell slippage/fees push the price down
+    }
+
+    #[test]
+    fn test_cancel_after_fill_fails() {
+        let monitor = monitor_with_price("SOL", 100.0);
+        let executor = SimulatedExecutor::new(monitor);
+
+        let result = executor
+            .submit(OrderParams { token: "SOL".to_string(), side: OrderSide::Buy, amount: 1.0, price: None })
+            .unwrap();
+
+        assert!(executor.cancel(&result.order_id).is_err());
+    }
+
+    #[test]
+    fn test_status_reports_filled() {
+        let monitor = monitor_with_price("SOL", 100.0);
+        let executor = SimulatedExecutor::new(monitor);
+
+        let result = executor
+            .submit(OrderParams { token: "SOL".to_string(), side: OrderSide::Buy, amount: 1.0, price: None })
+            .unwrap();
+
+        assert!(matches!(executor.status(&result.order_id).unwrap(), Some(OrderStatus::Filled)));
+        assert_eq!(executor.status("unknown").unwrap(), None);
+    }
+}
@@ -0,0 +1,243 @@
+//! Binance combined ticker stream feed
+//!
+//! Connects to Binance's public WebSocket API, subscribes to the 24hr
+//! ticker stream for a list of symbols, and converts each update into a
+//! `PriceData` point fed into the shared `PriceMonitor`.
+
+use super::{Backoff, WsPriceFeed};
+use crate::price_monitor::PriceMonitor;
+use crate::types::PriceStatus;
+use async_trait::async_trait;
+use chrono::Utc;
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::Message;
+
+const BINANCE_WS_URL: &str = "wss://stream.binance.com:9443/ws";
+
+/// A connection is considered stable, and the reconnect backoff resets,
+/// once it has stayed up for at least this long.
+const STABLE_CONNECTION: Duration = Duration::from_secs(60);
+
+/// Outcome of one connection attempt, used by `run` to decide whether to
+/// stop, reset the backoff, or reconnect with the existing backoff delay.
+enum RunOutcome {
+    /// `shutdown` fired; the caller should stop.
+    ShutdownRequested,
+    /// The connection dropped after staying up for `connected_for`. A
+    /// connection that was never established is reported as an `Err`.
+    Disconnected { connected_for: Duration },
+}
+
+/// Ticker payload as published on Binance's `<symbol>@ticker` stream.
+#[derive(Debug, Deserialize)]
+struct TickerFrame {
+    #[serde(rename = "e")]
+    event: Option<String>,
+    #[serde(rename = "s")]
+    symbol: Option<String>,
+    /// Last price
+    #[serde(rename = "c")]
+    close_price: Option<String>,
+}
+
+/// Subscription ack/error envelope Binance sends back for control messages.
+#[derive(Debug, Deserialize)]
+struct ControlFrame {
+    result: Option<serde_json::Value>,
+    error: Option<serde_json::Value>,
+    id: Option<u64>,
+}
+
+/// `WsPriceFeed` implementation backed by Binance's public ticker stream.
+pub struct BinanceWsFeed {
+    /// Confidence interval assumed for a ticker-derived price, since Binance
+    /// does not publish one directly.
+    pub assumed_conf_ratio: f64,
+}
+
+impl Default for BinanceWsFeed {
+    fn default() -> Self {
+        Self { assumed_conf_ratio: 0.0005 }
+    }
+}
+
+impl BinanceWsFeed {
+    fn stream_name(token: &str) -> String {
+        format!("{}usdt@ticker", token.to_lowercase())
+    }
+
+    fn parse_symbol(symbol: &str) -> String {
+        symbol.to_uppercase().trim_end_matches("USDT").to_string()
+    }
+
+    /// Run a single connection attempt: connect, subscribe, read until the
+    /// connection drops or `shutdown` fires. `shutdown` races `connect_async`
+    /// itself and every frame read, so a connection attempt that never
+    /// completes still observes shutdown; the subscribe and pong sends are
+    /// awaited without racing it.
+    async fn run_once(
+        &self,
+        monitor: &Arc<PriceMonitor>,
+        tokens: &[String],
+        shutdown: &mut oneshot::Receiver<()>,
+    ) -> Result<RunOutcome, String> {
+        let mut ws = tokio::select! {
+            _ = &mut *shutdown => return Ok(RunOutcome::ShutdownRequested),
+            result = tokio_tungstenite::connect_async(BINANCE_WS_URL) => {
+                result.map_err(|e| format!("binance ws connect failed: {e}"))?.0
+            }
+        };
+        let connected_at = Instant::now();
+
+        let params: Vec<String> = tokens.iter().map(|t| Self::stream_name(t)).collect();
+        let subscribe = json!({
+            "method": "SUBSCRIBE",
+            "params": params,
+            "id": 1,
+        });
+        ws.send(Message::Text(subscribe.to_string()))
+            .await
+            .map_err(|e| format!("binance subscribe failed: {e}"))?;
+
+        loop {
+            tokio::select! {
+                _ = &mut *shutdown => return Ok(RunOutcome::ShutdownRequested),
+                frame = ws.next() => {
+                    match frame {
+                        Some(Ok(Message::Text(text))) => self.handle_text(monitor, &text),
+                        Some(Ok(Message::Ping(payload))) => {
+                            // Heartbeat: Binance expects a pong echoing the payload.
+                            let _ = ws.send(Message::Pong(payload)).await;
+                        }
+                        Some(Ok(Message::Close(_))) | None => {
+                            return Ok(RunOutcome::Disconnected { connected_for: connected_at.elapsed() });
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(_)) => {
+                            return Ok(RunOutcome::Disconnected { connected_for: connected_at.elapsed() });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn handle_text(&self, monitor: &Arc<PriceMonitor>, text: &str) {
+        if let Ok(control) = serde_json::from_str::<ControlFrame>(text) {
+            if control.error.is_some() || control.id.is_some() {
+                // Subscription ack/error; nothing to feed into the monitor.
+                return;
+            }
+            let _ = control.result;
+        }
+
+        if let Ok(ticker) = serde_json::from_str::<TickerFrame>(text) {
+            if ticker.event.as_deref() != Some("24hrTicker") {
+                return;
+            }
+            let (Some(symbol), Some(close_price)) = (ticker.symbol, ticker.close_price) else {
+                return;
+            };
+            let Ok(price) = close_price.parse::<f64>() else {
+                return;
+            };
+
+            let token = Self::parse_symbol(&symbol);
+            let conf = price * self.assumed_conf_ratio;
+            let _ = monitor.update_price_from(token, "binance".to_string(), price, conf, PriceStatus::Trading, Utc::now());
+        }
+    }
+}
+
+#[async_trait]
+impl WsPriceFeed for BinanceWsFeed {
+    async fn run(
+        &self,
+        monitor: Arc<PriceMonitor>,
+        tokens: Vec<String>,
+        mut shutdown: oneshot::Receiver<()>,
+    ) -> Result<(), String> {
+        let mut backoff = Backoff::new(500, 30_000);
+
+        loop {
+            match self.run_once(&monitor, &tokens, &mut shutdown).await {
+                Ok(RunOutcome::ShutdownRequested) => return Ok(()),
+                Ok(RunOutcome::Disconnected { connected_for }) => {
+                    if connected_for >= STABLE_CONNECTION {
+                        backoff = Backoff::new(500, 30_000);
+                    }
+                }
+                Err(_) => {}
+            }
+
+            tokio::select! {
+                _ = &mut shutdown => return Ok(()),
+                _ = tokio::time::sleep(backoff.next_delay()) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_name_lowercases_and_appends_usdt() {
+        assert_eq!(BinanceWsFeed::stream_name("SOL"), "solusdt@ticker");
+    }
+
+    #[test]
+    fn test_parse_symbol_uppercases_and_strips_usdt() {
+        assert_eq!(BinanceWsFeed::parse_symbol("solusdt"), "SOL");
+    }
+
+    #[test]
+    fn test_handle_text_publishes_price_from_ticker_frame() {
+        let monitor = Arc::new(PriceMonitor::new());
+        let feed = BinanceWsFeed::default();
+        let text = r#"{"e":"24hrTicker","s":"SOLUSDT","c":"101.50"}"#;
+
+        feed.handle_text(&monitor, text);
+
+        assert_eq!(monitor.get_price("SOL").unwrap(), Some(101.50));
+    }
+
+    #[test]
+    fn test_handle_text_ignores_subscription_ack() {
+        let monitor = Arc::new(PriceMonitor::new());
+        let feed = BinanceWsFeed::default();
+        let text = r#"{"result":null,"id":1}"#;
+
+        feed.handle_text(&monitor, text);
+
+        assert_eq!(monitor.get_price("SOL").unwrap(), None);
+    }
+
+    #[test]
+    fn test_handle_text_ignores_subscription_error() {
+        let monitor = Arc::new(PriceMonitor::new());
+        let feed = BinanceWsFeed::default();
+        let text = r#"{"error":{"code":-1,"msg":"bad params"},"id":1}"#;
+
+        feed.handle_text(&monitor, text);
+
+        assert_eq!(monitor.get_price("SOL").unwrap(), None);
+    }
+
+    #[test]
+    fn test_handle_text_ignores_non_ticker_event() {
+        let monitor = Arc::new(PriceMonitor::new());
+        let feed = BinanceWsFeed::default();
+        let text = r#"{"e":"kline","s":"SOLUSDT","c":"101.50"}"#;
+
+        feed.handle_text(&monitor, text);
+
+        assert_eq!(monitor.get_price("SOL").unwrap(), None);
+    }
+}
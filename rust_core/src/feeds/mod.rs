@@ -0,0 +1,74 @@
+//! WebSocket price feed ingestion
+//!
+//! Connects to exchange ticker streams and feeds parsed `PriceData` into a
+//! shared `PriceMonitor`. Feeds handle subscription ack/error frames and
+//! heartbeats, and reconnect with backoff when the connection drops.
+//! Exchange system-status messages are not handled.
+
+mod binance;
+
+pub use binance::BinanceWsFeed;
+
+use crate::price_monitor::PriceMonitor;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::oneshot;
+
+/// A live source of price ticks for a set of tokens.
+///
+/// Implementations own the connection lifecycle: connecting, subscribing,
+/// parsing frames into `PriceData`, and reconnecting with backoff on
+/// disconnect. `run` returns once `shutdown` fires or an unrecoverable
+/// error occurs.
+#[async_trait]
+pub trait WsPriceFeed: Send + Sync {
+    /// Stream ticks for `tokens` into `monitor` until `shutdown` resolves.
+    async fn run(
+        &self,
+        monitor: Arc<PriceMonitor>,
+        tokens: Vec<String>,
+        shutdown: oneshot::Receiver<()>,
+    ) -> Result<(), String>;
+}
+
+/// Exponential backoff schedule used between reconnect attempts.
+pub(crate) struct Backoff {
+    attempt: u32,
+    base_ms: u64,
+    max_ms: u64,
+}
+
+impl Backoff {
+    pub(crate) fn new(base_ms: u64, max_ms: u64) -> Self {
+        Self { attempt: 0, base_ms, max_ms }
+    }
+
+    /// Delay for the next attempt, then record that the attempt happened.
+    pub(crate) fn next_delay(&mut self) -> std::time::Duration {
+        let ms = self.base_ms.saturating_mul(1 << self.attempt.min(10)).min(self.max_ms);
+        self.attempt += 1;
+        std::time::Duration::from_millis(ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_each_attempt() {
+        let mut backoff = Backoff::new(500, 30_000);
+        assert_eq!(backoff.next_delay(), std::time::Duration::from_millis(500));
+        assert_eq!(backoff.next_delay(), std::time::Duration::from_millis(1_000));
+        assert_eq!(backoff.next_delay(), std::time::Duration::from_millis(2_000));
+    }
+
+    #[test]
+    fn test_backoff_caps_at_max_ms() {
+        let mut backoff = Backoff::new(500, 2_000);
+        for _ in 0..10 {
+            backoff.next_delay();
+        }
+        assert_eq!(backoff.next_delay(), std::time::Duration::from_millis(2_000));
+    }
+}
@@ -7,14 +7,47 @@
 //!
 //! Integrated with Python via PyO3 bindings.
 
+use chrono::Duration;
+use once_cell::sync::Lazy;
+use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::runtime::Runtime;
+use tokio::sync::oneshot;
 
 mod types;
 mod price_monitor;
+mod feeds;
+mod execution;
+mod backtest;
 
 pub use types::*;
 pub use price_monitor::*;
+pub use feeds::*;
+pub use execution::*;
+pub use backtest::*;
+
+/// Background tokio runtime that drives every live feed task.
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    Runtime::new().expect("failed to start rust_core tokio runtime")
+});
+
+/// Shared price monitor that feed tasks publish into and pyfunctions read from.
+static MONITOR: Lazy<Arc<PriceMonitor>> = Lazy::new(|| Arc::new(PriceMonitor::new()));
+
+/// Shared order executor that Python order calls route through.
+static EXECUTOR: Lazy<SimulatedExecutor> = Lazy::new(|| SimulatedExecutor::new(MONITOR.clone()));
+
+/// A feed task spawned for one token, and the handle used to stop it.
+struct RunningFeed {
+    shutdown: oneshot::Sender<()>,
+    handle: tokio::task::JoinHandle<Result<(), String>>,
+}
+
+/// Feed tasks currently running, keyed by token.
+static FEEDS: Lazy<Mutex<HashMap<String, RunningFeed>>> = Lazy::new(|| Mutex::new(HashMap::new()));
 
 /// Get real-time price for a token
 ///
@@ -22,22 +55,13 @@ pub use price_monitor::*;
 /// * `token` - Token symbol (e.g., "SOL", "BTC")
 ///
 /// # Returns
-/// Current price as f64, or None if unavailable
+/// Current price as f64, or None if unavailable, or if no live feed has
+/// published a reading for it yet
 #[pyfunction]
 fn get_realtime_price(token: &str) -> PyResult<Option<f64>> {
-    // TODO: Connect to actual price feed
-    // For now, return mock data
-    let mock_prices = [
-        ("SOL", 145.50),
-        ("BTC", 97234.00),
-        ("ETH", 3456.78),
-    ];
-
-    let price = mock_prices.iter()
-        .find(|(symbol, _)| *symbol == token)
-        .map(|(_, price)| *price);
-
-    Ok(price)
+    MONITOR
+        .get_price(token)
+        .map_err(PyRuntimeError::new_err)
 }
 
 /// Get multiple token prices in parallel
@@ -62,11 +86,33 @@ fn get_bulk_prices(py: Python, tokens: Vec<String>) -> PyResult<PyObject> {
 
 /// Initialize price monitoring for a token
 ///
+/// Spawns a `BinanceWsFeed` task that streams ticks into the shared
+/// `PriceMonitor` until `stop_price_monitor` is called.
+///
 /// # Arguments
 /// * `token` - Token address or symbol
 /// * `threshold` - Percentage change threshold for alerts (e.g., 2.0 for 2%)
 #[pyfunction]
 fn start_price_monitor(token: &str, threshold: f64) -> PyResult<String> {
+    let mut feeds = FEEDS.lock().map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+    if feeds.contains_key(token) {
+        return Ok(format!("Price monitor already running for {}", token));
+    }
+
+    MONITOR
+        .add_alert(token.to_string(), threshold)
+        .map_err(PyRuntimeError::new_err)?;
+
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    let monitor = MONITOR.clone();
+    let feed = BinanceWsFeed::default();
+    let tokens = vec![token.to_string()];
+
+    let handle = RUNTIME.spawn(async move { feed.run(monitor, tokens, shutdown_rx).await });
+
+    feeds.insert(token.to_string(), RunningFeed { shutdown: shutdown_tx, handle });
+
     Ok(format!(
         "Price monitor started for {} with {}% threshold",
         token, threshold
@@ -76,16 +122,168 @@ fn start_price_monitor(token: &str, threshold: f64) -> PyResult<String> {
 /// Check if price monitor is running
 #[pyfunction]
 fn is_monitor_active(token: &str) -> PyResult<bool> {
-    // TODO: Implement actual monitoring state check
-    Ok(false)
+    let feeds = FEEDS.lock().map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    Ok(feeds.get(token).map(|f| !f.handle.is_finished()).unwrap_or(false))
 }
 
 /// Stop price monitoring for a token
 #[pyfunction]
 fn stop_price_monitor(token: &str) -> PyResult<()> {
+    let mut feeds = FEEDS.lock().map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+    if let Some(feed) = feeds.remove(token) {
+        let _ = feed.shutdown.send(());
+        RUNTIME.spawn(async move {
+            let _ = feed.handle.await;
+        });
+    }
+
+    let _ = MONITOR.remove_alert(token);
+
     Ok(())
 }
 
+fn order_status_str(status: OrderStatus) -> &'static str {
+    match status {
+        OrderStatus::Pending => "pending",
+        OrderStatus::PartiallyFilled => "partially_filled",
+        OrderStatus::Filled => "filled",
+        OrderStatus::Cancelled => "cancelled",
+        OrderStatus::Failed => "failed",
+    }
+}
+
+fn parse_side(side: &str) -> PyResult<OrderSide> {
+    match side.to_lowercase().as_str() {
+        "buy" => Ok(OrderSide::Buy),
+        "sell" => Ok(OrderSide::Sell),
+        other => Err(PyRuntimeError::new_err(format!("unknown order side: {other}"))),
+    }
+}
+
+fn order_result_to_dict(py: Python, result: &OrderResult) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("order_id", &result.order_id)?;
+    dict.set_item("status", order_status_str(result.status))?;
+    dict.set_item("filled_amount", result.filled_amount)?;
+    dict.set_item("average_price", result.average_price)?;
+    dict.set_item("latency_ms", result.latency_ms)?;
+    Ok(dict.into())
+}
+
+/// Submit an order through the shared execution engine
+///
+/// # Arguments
+/// * `token` - Token symbol to trade
+/// * `side` - `"buy"` or `"sell"`
+/// * `amount` - Order size
+/// * `price` - Limit price, or `None` for a market order
+///
+/// # Returns
+/// Dictionary with `order_id`, `status`, `filled_amount`, `average_price`, `latency_ms`
+#[pyfunction]
+fn submit_order(py: Python, token: &str, side: &str, amount: f64, price: Option<f64>) -> PyResult<PyObject> {
+    let params = OrderParams { token: token.to_string(), side: parse_side(side)?, amount, price };
+    let result = EXECUTOR.submit(params).map_err(PyRuntimeError::new_err)?;
+    order_result_to_dict(py, &result)
+}
+
+/// Cancel a previously submitted order
+#[pyfunction]
+fn cancel_order(order_id: &str) -> PyResult<()> {
+    EXECUTOR.cancel(order_id).map_err(PyRuntimeError::new_err)
+}
+
+/// Get the current status of a previously submitted order
+#[pyfunction]
+fn get_order_status(order_id: &str) -> PyResult<Option<String>> {
+    Ok(EXECUTOR
+        .status(order_id)
+        .map_err(PyRuntimeError::new_err)?
+        .map(order_status_str)
+        .map(str::to_string))
+}
+
+fn backtest_report_to_dict(py: Python, report: &BacktestReport) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("alerts_triggered", report.alerts_triggered)?;
+    dict.set_item("fills", report.fills)?;
+    dict.set_item("realized_pnl", report.realized_pnl)?;
+    dict.set_item("fees_paid", report.fees_paid)?;
+    dict.set_item("win_rate", report.win_rate)?;
+    dict.set_item("max_drawdown", report.max_drawdown)?;
+    Ok(dict.into())
+}
+
+/// Run a backtest of alert thresholds and order logic against recorded history
+///
+/// Replays the ticks in `path` (a `.csv` or `.json` file, see
+/// `backtest::load_ticks`) through a fresh `PriceMonitor` and
+/// `SimulatedExecutor` driven by a `ReplayClock`, so staleness gating sees
+/// each tick at its own historical time rather than wall-clock time.
+///
+/// Monitor gating and execution cost knobs default to the same values the
+/// live `MONITOR`/`EXECUTOR` use, but each can be overridden so a backtest
+/// can be tuned offline instead of being locked to the live defaults.
+///
+/// # Arguments
+/// * `path` - Path to a CSV or JSON file of historical ticks
+/// * `alert_threshold_percent` - Percentage change threshold for alerts
+/// * `trade_size` - Order size submitted on every triggered alert
+/// * `max_staleness_secs` - Override for `MonitorConfig::max_staleness`
+/// * `max_conf_ratio` - Override for `MonitorConfig::max_conf_ratio`
+/// * `min_quorum` - Override for `MonitorConfig::min_quorum`
+/// * `outlier_conf_widths` - Override for `MonitorConfig::outlier_conf_widths`
+/// * `slippage_bps` - Override for `ExecutionConfig::slippage_bps`
+/// * `fee_bps` - Override for `ExecutionConfig::fee_bps`
+///
+/// # Returns
+/// Dictionary with `alerts_triggered`, `fills`, `realized_pnl`, `fees_paid`,
+/// `win_rate`, `max_drawdown`
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+fn run_backtest(
+    py: Python,
+    path: &str,
+    alert_threshold_percent: f64,
+    trade_size: f64,
+    max_staleness_secs: Option<i64>,
+    max_conf_ratio: Option<f64>,
+    min_quorum: Option<usize>,
+    outlier_conf_widths: Option<f64>,
+    slippage_bps: Option<f64>,
+    fee_bps: Option<f64>,
+) -> PyResult<PyObject> {
+    let mut monitor_config = MonitorConfig::default();
+    if let Some(secs) = max_staleness_secs {
+        monitor_config.max_staleness = Duration::seconds(secs);
+    }
+    if let Some(ratio) = max_conf_ratio {
+        monitor_config.max_conf_ratio = ratio;
+    }
+    if let Some(quorum) = min_quorum {
+        monitor_config.min_quorum = quorum;
+    }
+    if let Some(widths) = outlier_conf_widths {
+        monitor_config.outlier_conf_widths = widths;
+    }
+
+    let mut execution_config = ExecutionConfig::default();
+    if let Some(bps) = slippage_bps {
+        execution_config.slippage_bps = bps;
+    }
+    if let Some(bps) = fee_bps {
+        execution_config.fee_bps = bps;
+    }
+
+    let ticks = load_ticks(path).map_err(PyRuntimeError::new_err)?;
+    let backtester = Backtester::with_execution_config(monitor_config, execution_config, trade_size);
+    let report = backtester
+        .run(&ticks, alert_threshold_percent)
+        .map_err(PyRuntimeError::new_err)?;
+    backtest_report_to_dict(py, &report)
+}
+
 /// Get version information
 #[pyfunction]
 fn version() -> PyResult<String> {
@@ -102,6 +300,14 @@ fn moon_rust_core(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(is_monitor_active, m)?)?;
     m.add_function(wrap_pyfunction!(stop_price_monitor, m)?)?;
 
+    // Order execution functions
+    m.add_function(wrap_pyfunction!(submit_order, m)?)?;
+    m.add_function(wrap_pyfunction!(cancel_order, m)?)?;
+    m.add_function(wrap_pyfunction!(get_order_status, m)?)?;
+
+    // Backtesting functions
+    m.add_function(wrap_pyfunction!(run_backtest, m)?)?;
+
     // Utility functions
     m.add_function(wrap_pyfunction!(version, m)?)?;
 
@@ -113,9 +319,48 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_get_realtime_price() {
-        assert_eq!(get_realtime_price("SOL").unwrap(), Some(145.50));
-        assert_eq!(get_realtime_price("BTC").unwrap(), Some(97234.00));
-        assert_eq!(get_realtime_price("INVALID").unwrap(), None);
+    fn test_get_realtime_price_without_feed() {
+        // No feed has been started, so nothing has published a price yet.
+        assert_eq!(get_realtime_price("NOFEED").unwrap(), None);
+    }
+
+    #[test]
+    fn test_monitor_lifecycle_without_network() {
+        // start/stop must manage monitor state correctly even though the
+        // underlying feed connection will fail in a sandboxed test run.
+        assert!(!is_monitor_active("LIFECYCLE").unwrap());
+        start_price_monitor("LIFECYCLE", 2.0).unwrap();
+        assert!(is_monitor_active("LIFECYCLE").unwrap());
+        stop_price_monitor("LIFECYCLE").unwrap();
+    }
+
+    #[test]
+    fn test_market_order_rejected_without_price() {
+        // No feed has published a price for this token, so a market order
+        // must be rejected rather than filling against nothing.
+        assert!(submit_order_err_for_no_feed());
+    }
+
+    fn submit_order_err_for_no_feed() -> bool {
+        EXECUTOR
+            .submit(OrderParams {
+                token: "NOFEED_LIB".to_string(),
+                side: OrderSide::Buy,
+                amount: 1.0,
+                price: None,
+            })
+            .is_err()
+    }
+
+    #[test]
+    fn test_order_status_round_trip() {
+        let params = OrderParams {
+            token: "ANY".to_string(),
+            side: OrderSide::Sell,
+            amount: 2.0,
+            price: Some(10.0),
+        };
+        let result = EXECUTOR.submit(params).unwrap();
+        assert_eq!(get_order_status(&result.order_id).unwrap(), Some("filled".to_string()));
     }
 }
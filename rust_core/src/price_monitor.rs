@@ -1,28 +1,128 @@
 //! Real-time price monitoring service
 //!
 //! Monitors multiple tokens simultaneously with WebSocket connections
-//! and triggers alerts on significant price changes.
+//! and triggers alerts on significant price changes. Each token may be fed
+//! by several independent sources; readings are pruned for staleness and
+//! outliers, then reduced to a single median consensus price before alert
+//! thresholds are evaluated, following the pre-price pruning pattern used
+//! by on-chain oracle pallets.
 
-use crate::types::{PriceData, PriceAlert};
-use chrono::Utc;
+use crate::types::{PriceData, PriceAlert, PriceStatus};
+use chrono::{DateTime, Duration, Utc};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
+/// Identifies which upstream source a reading came from (e.g. `"binance"`).
+pub type SourceId = String;
+
+/// A source of "now" for staleness checks.
+///
+/// Injectable so a backtest can replay historical ticks and have staleness
+/// gating behave exactly as it would have at the time each tick was
+/// published, instead of comparing against the real wall clock.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the real wall-clock time. Used outside of backtests.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock whose time is set explicitly by the caller, e.g. to the timestamp
+/// of the tick currently being replayed.
+pub struct ReplayClock {
+    now: RwLock<DateTime<Utc>>,
+}
+
+impl ReplayClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self { now: RwLock::new(start) }
+    }
+
+    /// Advance the clock to `now`.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.write().expect("replay clock lock poisoned") = now;
+    }
+}
+
+impl Clock for ReplayClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.read().expect("replay clock lock poisoned")
+    }
+}
+
+/// Tuning knobs for how strictly the monitor gates on staleness and confidence.
+#[derive(Debug, Clone)]
+pub struct MonitorConfig {
+    /// Maximum age a reading may have and still be considered fresh.
+    pub max_staleness: Duration,
+    /// Maximum `conf / price` ratio before a reading is considered too
+    /// uncertain to act on.
+    pub max_conf_ratio: f64,
+    /// Minimum number of sources that must survive pruning before a
+    /// consensus price is published.
+    pub min_quorum: usize,
+    /// A reading is pruned as an outlier if it deviates from the running
+    /// median by more than this many multiples of its own confidence width.
+    pub outlier_conf_widths: f64,
+}
+
+impl Default for MonitorConfig {
+    fn default() -> Self {
+        Self {
+            max_staleness: Duration::seconds(10),
+            max_conf_ratio: 0.02,
+            min_quorum: 1,
+            outlier_conf_widths: 5.0,
+        }
+    }
+}
+
 /// Global price monitor state
 pub struct PriceMonitor {
     /// Active price alerts
     alerts: Arc<RwLock<HashMap<String, PriceAlert>>>,
 
-    /// Latest prices
+    /// Latest reading received from each source, per token
+    raw_prices: Arc<RwLock<HashMap<String, HashMap<SourceId, PriceData>>>>,
+
+    /// Published consensus price per token, after pruning and aggregation
     prices: Arc<RwLock<HashMap<String, PriceData>>>,
+
+    /// Staleness/confidence/quorum gating configuration
+    config: MonitorConfig,
+
+    /// Source of "now" used for staleness checks; the real clock unless
+    /// this monitor is driving a backtest.
+    clock: Arc<dyn Clock>,
 }
 
 impl PriceMonitor {
-    /// Create a new price monitor
+    /// Create a new price monitor with default gating configuration
     pub fn new() -> Self {
+        Self::with_config(MonitorConfig::default())
+    }
+
+    /// Create a new price monitor with custom gating configuration
+    pub fn with_config(config: MonitorConfig) -> Self {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// Create a new price monitor with a custom gating configuration and
+    /// clock, e.g. a `ReplayClock` driven by a `Backtester`.
+    pub fn with_clock(config: MonitorConfig, clock: Arc<dyn Clock>) -> Self {
         Self {
             alerts: Arc::new(RwLock::new(HashMap::new())),
+            raw_prices: Arc::new(RwLock::new(HashMap::new())),
             prices: Arc::new(RwLock::new(HashMap::new())),
+            config,
+            clock,
         }
     }
 
@@ -34,6 +134,7 @@ impl PriceMonitor {
             token: token.clone(),
             threshold_percent,
             last_price: 0.0,  // Will be set on first price update
+            last_conf: 0.0,
             active: true,
         };
 
@@ -48,46 +149,157 @@ impl PriceMonitor {
         Ok(())
     }
 
-    /// Update price and check for alerts
-    pub fn update_price(&self, token: String, price: f64) -> Result<Option<f64>, String> {
-        // Update prices cache
-        let price_data = PriceData {
+    /// Update price from a single, unnamed source and check for alerts.
+    ///
+    /// Convenience wrapper around [`update_price_from`](Self::update_price_from)
+    /// for callers that only ever see one feed for a token.
+    pub fn update_price(
+        &self,
+        token: String,
+        price: f64,
+        conf: f64,
+        status: PriceStatus,
+        publish_time: DateTime<Utc>,
+    ) -> Result<Option<f64>, String> {
+        self.update_price_from(token, "default".to_string(), price, conf, status, publish_time)
+    }
+
+    /// Record a reading from `source` for `token`, then recompute the
+    /// multi-source consensus and check for alerts.
+    ///
+    /// The raw reading is always cached per-source. Consensus aggregation
+    /// drops readings that are stale, untradeable, or outliers relative to
+    /// the median, and only publishes (and evaluates alerts against) a new
+    /// consensus price once at least `min_quorum` sources survive pruning.
+    pub fn update_price_from(
+        &self,
+        token: String,
+        source: SourceId,
+        price: f64,
+        conf: f64,
+        status: PriceStatus,
+        publish_time: DateTime<Utc>,
+    ) -> Result<Option<f64>, String> {
+        let reading = PriceData {
             token: token.clone(),
             price,
-            timestamp: Utc::now(),
+            conf,
+            status,
+            timestamp: publish_time,
             volume_24h: None,
             change_24h: None,
         };
 
-        let mut prices = self.prices.write().map_err(|e| e.to_string())?;
-        prices.insert(token.clone(), price_data);
+        {
+            let mut raw_prices = self.raw_prices.write().map_err(|e| e.to_string())?;
+            raw_prices.entry(token.clone()).or_default().insert(source, reading);
+        }
+
+        let Some(consensus) = self.aggregate(&token)? else {
+            return Ok(None);
+        };
+
+        {
+            let mut prices = self.prices.write().map_err(|e| e.to_string())?;
+            prices.insert(token.clone(), consensus.clone());
+        }
 
         // Check for alerts
         let mut alerts = self.alerts.write().map_err(|e| e.to_string())?;
 
         if let Some(alert) = alerts.get_mut(&token) {
             if alert.last_price > 0.0 {
-                let change_percent = ((price - alert.last_price) / alert.last_price) * 100.0;
+                let change_percent = ((consensus.price - alert.last_price) / alert.last_price) * 100.0;
+                let bands_overlap =
+                    bands_overlap(consensus.price, consensus.conf, alert.last_price, alert.last_conf);
 
-                if change_percent.abs() >= alert.threshold_percent {
-                    alert.last_price = price;
+                if change_percent.abs() >= alert.threshold_percent && !bands_overlap {
+                    alert.last_price = consensus.price;
+                    alert.last_conf = consensus.conf;
                     return Ok(Some(change_percent));
                 }
             } else {
                 // First price update
-                alert.last_price = price;
+                alert.last_price = consensus.price;
+                alert.last_conf = consensus.conf;
             }
         }
 
         Ok(None)
     }
 
+    /// Prune stale/untradeable/outlier readings for `token` and reduce the
+    /// survivors to a single median consensus reading, or `None` if fewer
+    /// than `min_quorum` readings survive.
+    fn aggregate(&self, token: &str) -> Result<Option<PriceData>, String> {
+        let raw_prices = self.raw_prices.read().map_err(|e| e.to_string())?;
+        let Some(readings) = raw_prices.get(token) else {
+            return Ok(None);
+        };
+
+        let now = self.clock.now();
+        let candidates: Vec<&PriceData> = readings
+            .values()
+            .filter(|r| r.status == PriceStatus::Trading)
+            .filter(|r| now - r.timestamp <= self.config.max_staleness)
+            .filter(|r| r.price != 0.0 && (r.conf / r.price).abs() <= self.config.max_conf_ratio)
+            .collect();
+
+        if candidates.is_empty() {
+            return Ok(None);
+        }
+
+        let running_median = median(&candidates.iter().map(|r| r.price).collect::<Vec<_>>());
+
+        let survivors: Vec<&PriceData> = candidates
+            .into_iter()
+            .filter(|r| {
+                let width = r.conf * self.config.outlier_conf_widths;
+                (r.price - running_median).abs() <= width
+            })
+            .collect();
+
+        if survivors.len() < self.config.min_quorum {
+            return Ok(None);
+        }
+
+        let consensus_price = median(&survivors.iter().map(|r| r.price).collect::<Vec<_>>());
+        let consensus_conf = median(&survivors.iter().map(|r| r.conf).collect::<Vec<_>>());
+        let latest_timestamp = survivors.iter().map(|r| r.timestamp).max().unwrap();
+
+        Ok(Some(PriceData {
+            token: token.to_string(),
+            price: consensus_price,
+            conf: consensus_conf,
+            status: PriceStatus::Trading,
+            timestamp: latest_timestamp,
+            volume_24h: None,
+            change_24h: None,
+        }))
+    }
+
     /// Get current price for a token
     pub fn get_price(&self, token: &str) -> Result<Option<f64>, String> {
         let prices = self.prices.read().map_err(|e| e.to_string())?;
         Ok(prices.get(token).map(|p| p.price))
     }
 
+    /// Get the current price, confidence interval, and trading status for a token
+    pub fn get_price_with_conf(&self, token: &str) -> Result<Option<(f64, f64, PriceStatus)>, String> {
+        let prices = self.prices.read().map_err(|e| e.to_string())?;
+        Ok(prices.get(token).map(|p| (p.price, p.conf, p.status)))
+    }
+
+    /// Whether the latest reading for `token` is fresh enough to act on,
+    /// i.e. within `max_staleness` of now.
+    pub fn is_price_fresh(&self, token: &str) -> Result<bool, String> {
+        let prices = self.prices.read().map_err(|e| e.to_string())?;
+        Ok(prices
+            .get(token)
+            .map(|p| self.clock.now() - p.timestamp <= self.config.max_staleness)
+            .unwrap_or(false))
+    }
+
     /// Get all monitored tokens
     pub fn get_monitored_tokens(&self) -> Result<Vec<String>, String> {
         let alerts = self.alerts.read().map_err(|e| e.to_string())?;
@@ -101,10 +313,38 @@ impl Default for PriceMonitor {
     }
 }
 
+/// Whether the confidence bands `[price_a ± conf_a]` and `[price_b ± conf_b]`
+/// overlap at all. An alert only fires once the new and previously published
+/// bands are fully separated, so noise within the confidence interval can't
+/// masquerade as a real price move.
+fn bands_overlap(price_a: f64, conf_a: f64, price_b: f64, conf_b: f64) -> bool {
+    let (low_a, high_a) = (price_a - conf_a, price_a + conf_a);
+    let (low_b, high_b) = (price_b - conf_b, price_b + conf_b);
+    low_a.max(low_b) <= high_a.min(high_b)
+}
+
+/// Median of a non-empty slice of values. Panics on an empty slice; callers
+/// are expected to have already checked for at least one survivor.
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).expect("prices must not be NaN"));
+
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn fresh(price: f64, conf: f64) -> (f64, f64, PriceStatus, DateTime<Utc>) {
+        (price, conf, PriceStatus::Trading, Utc::now())
+    }
+
     #[test]
     fn test_price_monitor_basic() {
         let monitor = PriceMonitor::new();
@@ -113,17 +353,20 @@ mod tests {
         assert!(monitor.add_alert("SOL".to_string(), 2.0).is_ok());
 
         // First update
-        let result = monitor.update_price("SOL".to_string(), 100.0);
+        let (price, conf, status, ts) = fresh(100.0, 0.1);
+        let result = monitor.update_price("SOL".to_string(), price, conf, status, ts);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), None); // No alert on first update
 
         // Small change (should not trigger)
-        let result = monitor.update_price("SOL".to_string(), 101.0);
+        let (price, conf, status, ts) = fresh(101.0, 0.1);
+        let result = monitor.update_price("SOL".to_string(), price, conf, status, ts);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), None);
 
         // Large change (should trigger)
-        let result = monitor.update_price("SOL".to_string(), 103.0);
+        let (price, conf, status, ts) = fresh(103.0, 0.1);
+        let result = monitor.update_price("SOL".to_string(), price, conf, status, ts);
         assert!(result.is_ok());
         assert!(result.unwrap().is_some());
     }
@@ -131,7 +374,8 @@ mod tests {
     #[test]
     fn test_get_price() {
         let monitor = PriceMonitor::new();
-        monitor.update_price("SOL".to_string(), 145.50).unwrap();
+        let (price, conf, status, ts) = fresh(145.50, 0.05);
+        monitor.update_price("SOL".to_string(), price, conf, status, ts).unwrap();
 
         let price = monitor.get_price("SOL").unwrap();
         assert_eq!(price, Some(145.50));
@@ -139,4 +383,130 @@ mod tests {
         let missing = monitor.get_price("BTC").unwrap();
         assert_eq!(missing, None);
     }
+
+    #[test]
+    fn test_get_price_with_conf() {
+        let monitor = PriceMonitor::new();
+        let (price, conf, status, ts) = fresh(145.50, 0.25);
+        monitor.update_price("SOL".to_string(), price, conf, status, ts).unwrap();
+
+        assert_eq!(
+            monitor.get_price_with_conf("SOL").unwrap(),
+            Some((145.50, 0.25, PriceStatus::Trading))
+        );
+    }
+
+    #[test]
+    fn test_halted_status_skips_alert() {
+        let monitor = PriceMonitor::new();
+        monitor.add_alert("SOL".to_string(), 2.0).unwrap();
+
+        monitor.update_price("SOL".to_string(), 100.0, 0.1, PriceStatus::Trading, Utc::now()).unwrap();
+        let result = monitor
+            .update_price("SOL".to_string(), 200.0, 0.1, PriceStatus::Halted, Utc::now())
+            .unwrap();
+
+        assert_eq!(result, None); // Halted status must not fire an alert
+    }
+
+    #[test]
+    fn test_stale_update_skips_alert() {
+        let monitor = PriceMonitor::new();
+        monitor.add_alert("SOL".to_string(), 2.0).unwrap();
+
+        monitor.update_price("SOL".to_string(), 100.0, 0.1, PriceStatus::Trading, Utc::now()).unwrap();
+
+        let stale_time = Utc::now() - Duration::minutes(5);
+        let result = monitor
+            .update_price("SOL".to_string(), 200.0, 0.1, PriceStatus::Trading, stale_time)
+            .unwrap();
+
+        assert_eq!(result, None); // Stale publish_time must not fire an alert
+
+        let (_, _, _, ts) = fresh(100.0, 0.1);
+        monitor.update_price("SOL".to_string(), 100.0, 0.1, PriceStatus::Trading, ts).unwrap();
+        assert!(monitor.is_price_fresh("SOL").unwrap());
+    }
+
+    #[test]
+    fn test_wide_confidence_skips_alert() {
+        let monitor = PriceMonitor::new();
+        monitor.add_alert("SOL".to_string(), 2.0).unwrap();
+
+        monitor.update_price("SOL".to_string(), 100.0, 0.1, PriceStatus::Trading, Utc::now()).unwrap();
+        // conf / price = 50 / 200 = 0.25, far above the default 0.02 ratio
+        let result = monitor
+            .update_price("SOL".to_string(), 200.0, 50.0, PriceStatus::Trading, Utc::now())
+            .unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_overlapping_confidence_bands_skips_alert() {
+        // conf / price = 0.05 here, so loosen the ratio gate to let both
+        // readings reach the band-overlap check.
+        let monitor = PriceMonitor::with_config(MonitorConfig {
+            max_conf_ratio: 0.1,
+            ..MonitorConfig::default()
+        });
+        monitor.add_alert("SOL".to_string(), 2.0).unwrap();
+
+        // Wide bands: [100-5, 100+5] = [95, 105]
+        monitor.update_price("SOL".to_string(), 100.0, 5.0, PriceStatus::Trading, Utc::now()).unwrap();
+
+        // 4% move clears the percent threshold, but [104-5, 104+5] = [99, 109]
+        // still overlaps the previous band, so this must not fire.
+        let result = monitor
+            .update_price("SOL".to_string(), 104.0, 5.0, PriceStatus::Trading, Utc::now())
+            .unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_quorum_not_met_without_enough_sources() {
+        let monitor = PriceMonitor::with_config(MonitorConfig {
+            min_quorum: 2,
+            ..MonitorConfig::default()
+        });
+
+        let result = monitor
+            .update_price_from("SOL".to_string(), "binance".to_string(), 100.0, 0.1, PriceStatus::Trading, Utc::now())
+            .unwrap();
+
+        assert_eq!(result, None);
+        assert_eq!(monitor.get_price("SOL").unwrap(), None); // no consensus published yet
+    }
+
+    #[test]
+    fn test_median_consensus_across_sources() {
+        let monitor = PriceMonitor::with_config(MonitorConfig {
+            min_quorum: 2,
+            ..MonitorConfig::default()
+        });
+
+        monitor.update_price_from("SOL".to_string(), "binance".to_string(), 100.0, 1.0, PriceStatus::Trading, Utc::now()).unwrap();
+        monitor.update_price_from("SOL".to_string(), "coinbase".to_string(), 102.0, 1.0, PriceStatus::Trading, Utc::now()).unwrap();
+        monitor.update_price_from("SOL".to_string(), "kraken".to_string(), 101.0, 1.0, PriceStatus::Trading, Utc::now()).unwrap();
+
+        assert_eq!(monitor.get_price("SOL").unwrap(), Some(101.0)); // median of 100/101/102
+    }
+
+    #[test]
+    fn test_outlier_source_pruned_from_consensus() {
+        let monitor = PriceMonitor::with_config(MonitorConfig {
+            min_quorum: 2,
+            outlier_conf_widths: 5.0,
+            ..MonitorConfig::default()
+        });
+
+        monitor.update_price_from("SOL".to_string(), "binance".to_string(), 100.0, 0.1, PriceStatus::Trading, Utc::now()).unwrap();
+        monitor.update_price_from("SOL".to_string(), "coinbase".to_string(), 100.2, 0.1, PriceStatus::Trading, Utc::now()).unwrap();
+        // Way off from the other two and far more than 5 confidence widths away
+        monitor.update_price_from("SOL".to_string(), "kraken".to_string(), 250.0, 0.1, PriceStatus::Trading, Utc::now()).unwrap();
+
+        let (price, _, _) = monitor.get_price_with_conf("SOL").unwrap().unwrap();
+        assert!((price - 100.1).abs() < 0.001); // consensus from the two agreeing sources only
+    }
 }
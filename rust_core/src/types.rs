@@ -3,25 +3,45 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+/// Trading status of a price feed, as reported by the upstream source.
+///
+/// Mirrors the kind of status flag Pyth-style oracles attach to a price so
+/// consumers can tell a live quote from one that should not be acted on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceStatus {
+    /// The feed is live and the price can be acted on.
+    Trading,
+    /// Trading has been halted for this token.
+    Halted,
+    /// The market is in an auction/opening phase; price is not tradeable.
+    Auction,
+    /// Status could not be determined; treat as untradeable.
+    Unknown,
+}
+
 /// Price data point
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceData {
     pub token: String,
     pub price: f64,
+    /// Symmetric confidence interval around `price`, in the same units.
+    pub conf: f64,
+    pub status: PriceStatus,
+    /// When this price was published by the source (not when we observed it).
     pub timestamp: DateTime<Utc>,
     pub volume_24h: Option<f64>,
     pub change_24h: Option<f64>,
 }
 
 /// Order side
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderSide {
     Buy,
     Sell,
 }
 
 /// Order status
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderStatus {
     Pending,
     PartiallyFilled,
@@ -55,5 +75,8 @@ pub struct PriceAlert {
     pub token: String,
     pub threshold_percent: f64,
     pub last_price: f64,
+    /// Confidence interval of `last_price` at the time it was published,
+    /// used to gate alerts on non-overlapping confidence bands.
+    pub last_conf: f64,
     pub active: bool,
 }